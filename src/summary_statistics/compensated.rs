@@ -0,0 +1,155 @@
+//! Compensated-summation summary statistics.
+use crate::errors::EmptyInput;
+use ndarray::{Data, Dimension};
+use num_traits::{Float, FromPrimitive};
+
+/// Extension trait for `ArrayBase` providing summary statistics computed with
+/// [Kahan–Babuška–Neumaier compensated summation] in place of the naive summation used by
+/// [`SummaryStatisticsExt`], trading a constant-factor overhead for a much smaller rounding
+/// error on the running sum.
+///
+/// [Kahan–Babuška–Neumaier compensated summation]: https://en.wikipedia.org/wiki/Kahan_summation_algorithm#Further_enhancements
+/// [`SummaryStatisticsExt`]: crate::SummaryStatisticsExt
+pub trait CompensatedSummationExt<A, S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Returns the [`arithmetic mean`] x̅ of all elements in the array, computed using
+    /// compensated summation.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of elements in the array.
+    ///
+    /// [`arithmetic mean`]: https://en.wikipedia.org/wiki/Arithmetic_mean
+    fn mean_kbn(&self) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive;
+
+    /// Returns the *p*-th central moment of all elements in the array, μₚ, computed using
+    /// compensated summation for both the mean and the sum of powers, see [`central_moment`]
+    /// for more details.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of elements in the array.
+    ///
+    /// [`central_moment`]: crate::SummaryStatisticsExt::central_moment
+    fn central_moment_kbn(&self, order: u16) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive;
+}
+
+impl<A, S, D> CompensatedSummationExt<A, S, D> for ndarray::ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn mean_kbn(&self) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        let n = self.len();
+        if n == 0 {
+            return Err(EmptyInput);
+        }
+        let sum = self.iter().copied().collect::<KbnSum<A>>().total();
+        Ok(sum / A::from_usize(n).expect("Converting number of elements to `A` must not fail."))
+    }
+
+    fn central_moment_kbn(&self, order: u16) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        let n = self.len();
+        if n == 0 {
+            return Err(EmptyInput);
+        }
+        if order == 0 {
+            return Ok(A::one());
+        }
+        let mean = self.mean_kbn()?;
+        let sum = self
+            .iter()
+            .map(|&x| (x - mean).powi(order as i32))
+            .collect::<KbnSum<A>>()
+            .total();
+        Ok(sum / A::from_usize(n).expect("Converting number of elements to `A` must not fail."))
+    }
+}
+
+/// A running [Kahan–Babuška–Neumaier] sum: a running `sum` together with a compensation term
+/// `c` that recovers the low-order bits lost to rounding on each addition.
+///
+/// [Kahan–Babuška–Neumaier]: https://en.wikipedia.org/wiki/Kahan_summation_algorithm#Further_enhancements
+struct KbnSum<A> {
+    sum: A,
+    compensation: A,
+}
+
+impl<A: Float> KbnSum<A> {
+    fn new() -> Self {
+        KbnSum {
+            sum: A::zero(),
+            compensation: A::zero(),
+        }
+    }
+
+    fn add(&mut self, x: A) {
+        let t = self.sum + x;
+        self.compensation = self.compensation
+            + if self.sum.abs() >= x.abs() {
+                (self.sum - t) + x
+            } else {
+                (x - t) + self.sum
+            };
+        self.sum = t;
+    }
+
+    fn total(self) -> A {
+        self.sum + self.compensation
+    }
+}
+
+impl<A: Float> std::iter::FromIterator<A> for KbnSum<A> {
+    fn from_iter<I: IntoIterator<Item = A>>(iter: I) -> Self {
+        let mut acc = KbnSum::new();
+        for x in iter {
+            acc.add(x);
+        }
+        acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CompensatedSummationExt;
+    use ndarray::Array1;
+
+    #[test]
+    fn mean_kbn_matches_naive_mean_on_well_conditioned_data() {
+        let arr = Array1::from_vec(vec![1.0f64, 2.0, 3.0, 4.0, 5.0]);
+        assert!((arr.mean_kbn().unwrap() - 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn mean_kbn_is_more_accurate_than_naive_summation() {
+        // A large value followed by many small ones: naively summing left-to-right in f32
+        // rounds away most of the small increments, while compensated summation recovers them.
+        let mut values = vec![1.0e8_f32];
+        values.extend(std::iter::repeat_n(1.0_f32, 1000));
+        let arr = Array1::from_vec(values.clone());
+
+        let true_sum = 1.0e8_f64 + 1000.0;
+        let naive_sum: f32 = values.iter().fold(0.0_f32, |acc, &x| acc + x);
+        let kbn_sum = arr.mean_kbn().unwrap() * values.len() as f32;
+
+        let naive_error = (naive_sum as f64 - true_sum).abs();
+        let kbn_error = (kbn_sum as f64 - true_sum).abs();
+        assert!(
+            kbn_error < naive_error,
+            "compensated summation (error {kbn_error}) should beat naive summation (error {naive_error})"
+        );
+    }
+}
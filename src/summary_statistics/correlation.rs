@@ -0,0 +1,145 @@
+//! Covariance and correlation between two arrays.
+use crate::errors::EmptyInput;
+use ndarray::{ArrayBase, Data, Dimension, Zip};
+use num_traits::{Float, FromPrimitive};
+
+/// Extension trait for `ArrayBase` providing methods to compute the [covariance] and
+/// [Pearson correlation] between two arrays of matching shape.
+///
+/// [covariance]: https://en.wikipedia.org/wiki/Covariance
+/// [Pearson correlation]: https://en.wikipedia.org/wiki/Pearson_correlation_coefficient
+pub trait CorrelationExt<A, S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Returns the sample covariance between `self` and `other`:
+    ///
+    /// ```text
+    ///          1    n
+    /// Cov(X,Y)=―――  ∑ (xᵢ-x̅)(yᵢ-y̅)
+    ///         n-1  i=1
+    /// ```
+    ///
+    /// If `self` and `other` contain fewer than 2 elements, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `self` and `other` don't have the same shape, or if `A::from_usize()` fails
+    /// to convert the number of elements.
+    fn covariance<S2>(&self, other: &ArrayBase<S2, D>) -> Result<A, EmptyInput>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive;
+
+    /// Returns the [Pearson correlation coefficient] between `self` and `other`:
+    ///
+    /// ```text
+    ///             Cov(X,Y)
+    /// ρ(X,Y) = ―――――――――――――
+    ///          √(Var(X)Var(Y))
+    /// ```
+    ///
+    /// If `self` and `other` contain fewer than 2 elements, `Err(EmptyInput)` is returned.
+    /// If either `self` or `other` has zero variance, `Err(EmptyInput)` is returned, since the
+    /// correlation is undefined in that case (rather than silently returning `NaN`).
+    ///
+    /// **Panics** if `self` and `other` don't have the same shape, or if `A::from_usize()` fails
+    /// to convert the number of elements.
+    ///
+    /// [Pearson correlation coefficient]: https://en.wikipedia.org/wiki/Pearson_correlation_coefficient
+    fn pearson_correlation<S2>(&self, other: &ArrayBase<S2, D>) -> Result<A, EmptyInput>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive;
+}
+
+impl<A, S, D> CorrelationExt<A, S, D> for ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn covariance<S2>(&self, other: &ArrayBase<S2, D>) -> Result<A, EmptyInput>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive,
+    {
+        let (n, _, _, c) = co_moments(self, other)?;
+        Ok(c / (n - A::one()))
+    }
+
+    fn pearson_correlation<S2>(&self, other: &ArrayBase<S2, D>) -> Result<A, EmptyInput>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive,
+    {
+        let (_, sxx, syy, c) = co_moments(self, other)?;
+        let denominator = (sxx * syy).sqrt();
+        if denominator.is_zero() {
+            return Err(EmptyInput);
+        }
+        Ok(c / denominator)
+    }
+}
+
+/// Runs the single-pass co-moment accumulation, returning `(n, Sxx, Syy, C)` where `Sxx` and
+/// `Syy` are the running sums of squared deviations of `x` and `y` respectively, and `C` is the
+/// running sum of products of deviations, `Σ(xᵢ-mean_x)(yᵢ-mean_y)`.
+fn co_moments<A, S, S2, D>(
+    x: &ArrayBase<S, D>,
+    y: &ArrayBase<S2, D>,
+) -> Result<(A, A, A, A), EmptyInput>
+where
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+    A: Float + FromPrimitive,
+{
+    assert_eq!(
+        x.shape(),
+        y.shape(),
+        "`self` and `other` must have the same shape"
+    );
+    if x.len() < 2 {
+        return Err(EmptyInput);
+    }
+    let mut n = A::zero();
+    let mut mean_x = A::zero();
+    let mut mean_y = A::zero();
+    let mut sxx = A::zero();
+    let mut syy = A::zero();
+    let mut c = A::zero();
+    Zip::from(x).and(y).for_each(|&xi, &yi| {
+        n = n + A::one();
+        let delta_x = xi - mean_x;
+        mean_x = mean_x + delta_x / n;
+        let delta_y_before = yi - mean_y;
+        mean_y = mean_y + delta_y_before / n;
+        let delta_y_after = yi - mean_y;
+        sxx = sxx + delta_x * (xi - mean_x);
+        syy = syy + delta_y_before * delta_y_after;
+        c = c + delta_x * delta_y_after;
+    });
+    Ok((n, sxx, syy, c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CorrelationExt;
+    use ndarray::array;
+
+    #[test]
+    fn covariance_and_correlation_of_known_data() {
+        let x = array![1.0f64, 2.0, 3.0, 4.0, 5.0];
+        let y = array![2.0f64, 4.0, 5.0, 4.0, 5.0];
+
+        // Expected values computed directly from the sample covariance/correlation formulas.
+        assert!((x.covariance(&y).unwrap() - 1.5).abs() < 1e-9);
+        assert!((x.pearson_correlation(&y).unwrap() - 0.7745966692414834).abs() < 1e-9);
+    }
+
+    #[test]
+    fn zero_variance_correlation_is_an_error() {
+        let x = array![1.0f64, 2.0, 3.0];
+        let constant = array![4.0f64, 4.0, 4.0];
+        assert!(x.pearson_correlation(&constant).is_err());
+    }
+}
@@ -0,0 +1,196 @@
+use super::SummaryStatisticsExt;
+use crate::errors::EmptyInput;
+use ndarray::{ArrayBase, Data, Dimension};
+use num_traits::{Float, FromPrimitive, Zero};
+use std::collections::HashMap;
+use std::ops::{Add, Div};
+
+impl<A, S, D> SummaryStatisticsExt<A, S, D> for ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn mean(&self) -> Result<A, EmptyInput>
+    where
+        A: Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero,
+    {
+        let n_elements = self.len();
+        if n_elements == 0 {
+            Err(EmptyInput)
+        } else {
+            let n_elements = A::from_usize(n_elements)
+                .expect("Converting number of elements to `A` must not fail.");
+            Ok(self.sum() / n_elements)
+        }
+    }
+
+    fn harmonic_mean(&self) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        Ok(SummaryStatisticsExt::mean(&self.map(|x| x.recip()))?.recip())
+    }
+
+    fn geometric_mean(&self) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        Ok(SummaryStatisticsExt::mean(&self.map(|x| x.ln()))?.exp())
+    }
+
+    fn kurtosis(&self) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        let moments = self.central_moments(4)?;
+        Ok(moments[4] / (moments[2] * moments[2]))
+    }
+
+    fn skewness(&self) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        let moments = self.central_moments(3)?;
+        Ok(moments[3] / moments[2].powf(A::from_f64(1.5).unwrap()))
+    }
+
+    fn central_moment(&self, order: u16) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        Ok(self.central_moments(order)?[order as usize])
+    }
+
+    fn central_moments(&self, order: u16) -> Result<Vec<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        let n = self.len();
+        if n == 0 {
+            return Err(EmptyInput);
+        }
+        let mean = SummaryStatisticsExt::mean(self).unwrap();
+        let n_elements =
+            A::from_usize(n).expect("Converting number of elements to `A` must not fail.");
+
+        let mut moments = vec![A::zero(); order as usize + 1];
+        moments[0] = A::one();
+        for &elem in self.iter() {
+            let deviation = elem - mean;
+            let mut power = A::one();
+            for moment in moments.iter_mut().skip(1) {
+                power = power * deviation;
+                *moment = *moment + power;
+            }
+        }
+        for moment in moments.iter_mut().skip(1) {
+            *moment = *moment / n_elements;
+        }
+        // Corrected two-pass: re-centre the raw moments of the deviations on their own mean,
+        // `moments[1]`, which is zero up to the rounding error of the first pass.
+        let raw = moments.clone();
+        let correction = -raw.get(1).copied().unwrap_or_else(A::zero);
+        for p in 1..raw.len() {
+            let (mut sum, mut coefficient, mut power) = (A::zero(), A::one(), A::one());
+            for k in 0..=p {
+                sum = sum + coefficient * raw[p - k] * power;
+                coefficient = coefficient * A::from_usize(p - k).unwrap()
+                    / A::from_usize(k + 1).unwrap();
+                power = power * correction;
+            }
+            moments[p] = sum;
+        }
+        Ok(moments)
+    }
+
+    fn root_mean_square(&self) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        Ok(SummaryStatisticsExt::mean(&self.map(|x| x.powi(2)))?.sqrt())
+    }
+
+    fn mean_absolute_deviation(&self) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        let mean = SummaryStatisticsExt::mean(self)?;
+        SummaryStatisticsExt::mean(&self.map(|x| (*x - mean).abs()))
+    }
+
+    fn range(&self) -> Result<A, EmptyInput>
+    where
+        A: Float,
+    {
+        let mut iter = self.iter();
+        let first = match iter.next() {
+            Some(x) => *x,
+            None => return Err(EmptyInput),
+        };
+        let (min, max) = iter.fold((first, first), |(min, max), &x| (min.min(x), max.max(x)));
+        Ok(max - min)
+    }
+
+    fn mode(&self) -> Result<A, EmptyInput>
+    where
+        A: Clone + Ord + std::hash::Hash,
+    {
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        let mut counts: HashMap<A, usize> = HashMap::new();
+        for elem in self.iter() {
+            *counts.entry(elem.clone()).or_insert(0) += 1;
+        }
+        let mode = counts
+            .into_iter()
+            .max_by_key(|(value, count)| (*count, std::cmp::Reverse(value.clone())))
+            .map(|(value, _)| value)
+            .expect("array was checked to be non-empty");
+        Ok(mode)
+    }
+
+    private_impl! {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SummaryStatisticsExt;
+    use ndarray::array;
+
+    #[test]
+    fn root_mean_square_matches_direct_computation() {
+        let a = array![1.0f64, 2.0, 3.0, 4.0];
+        let expected = (a.iter().map(|x| x.powi(2)).sum::<f64>() / a.len() as f64).sqrt();
+        assert!((a.root_mean_square().unwrap() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn mean_absolute_deviation_matches_direct_computation() {
+        let a = array![1.0f64, 2.0, 3.0, 10.0];
+        let mean = SummaryStatisticsExt::mean(&a).unwrap();
+        let expected = a.iter().map(|x| (x - mean).abs()).sum::<f64>() / a.len() as f64;
+        assert!((a.mean_absolute_deviation().unwrap() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn range_of_known_data() {
+        let a = array![3.0f64, -1.0, 4.0, 1.0, 5.0];
+        assert_eq!(a.range().unwrap(), 6.0);
+    }
+
+    #[test]
+    fn mode_of_known_data() {
+        let a = array![1, 2, 2, 3, 3, 3];
+        assert_eq!(a.mode().unwrap(), 3);
+    }
+
+    #[test]
+    fn empty_array_is_an_error() {
+        let a: ndarray::Array1<f64> = array![];
+        assert!(SummaryStatisticsExt::mean(&a).is_err());
+        assert!(a.root_mean_square().is_err());
+        assert!(a.mean_absolute_deviation().is_err());
+        assert!(a.range().is_err());
+    }
+}
@@ -141,7 +141,75 @@ where
     where
         A: Float + FromPrimitive;
 
+    /// Returns the [root mean square] (quadratic mean) of all elements in the array:
+    ///
+    /// ```text
+    ///          ⎛1   n    ⎞
+    /// RMS(X) = ⎜―   ∑ xᵢ²⎟^(1/2)
+    ///          ⎝n  i=1   ⎠
+    /// ```
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of elements in the array.
+    ///
+    /// [root mean square]: https://en.wikipedia.org/wiki/Root_mean_square
+    fn root_mean_square(&self) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive;
+
+    /// Returns the [mean absolute deviation] of all elements in the array around their mean:
+    ///
+    /// ```text
+    ///      1   n
+    /// MAD = ―   ∑ |xᵢ-x̅|
+    ///      n  i=1
+    /// ```
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// This is computed two-pass: the mean is computed first, then the average absolute
+    /// deviation from it.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of elements in the array.
+    ///
+    /// [mean absolute deviation]: https://en.wikipedia.org/wiki/Average_absolute_deviation
+    fn mean_absolute_deviation(&self) -> Result<A, EmptyInput>
+    where
+        A: Float + FromPrimitive;
+
+    /// Returns the [range] (max - min) of all elements in the array, tracking both extrema
+    /// in a single pass.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// [range]: https://en.wikipedia.org/wiki/Range_(statistics)
+    fn range(&self) -> Result<A, EmptyInput>
+    where
+        A: Float;
+
+    /// Returns the [mode] of all elements in the array: the most frequently occurring value.
+    /// If several values are tied for the highest frequency, the smallest of them is returned.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// [mode]: https://en.wikipedia.org/wiki/Mode_(statistics)
+    fn mode(&self) -> Result<A, EmptyInput>
+    where
+        A: Clone + Ord + std::hash::Hash;
+
     private_decl! {}
 }
 
+mod compensated;
+mod correlation;
 mod means;
+mod moments_accumulator;
+mod rolling;
+mod weighted;
+
+pub use compensated::CompensatedSummationExt;
+pub use correlation::CorrelationExt;
+pub use moments_accumulator::MomentsAccumulator;
+pub use rolling::RollingSummaryStatisticsExt;
+pub use weighted::WeightedSummaryStatisticsExt;
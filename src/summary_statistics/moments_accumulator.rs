@@ -0,0 +1,250 @@
+//! Online, mergeable accumulator for central moments.
+use crate::errors::EmptyInput;
+use num_traits::{Float, FromPrimitive, Zero};
+
+/// An online, mergeable accumulator for the first `p` central moments of a stream of values.
+///
+/// Unlike [`SummaryStatisticsExt::central_moment`], which requires the whole array to be held
+/// in memory, `MomentsAccumulator` updates its state one value at a time via [`push`] and can
+/// combine two independently accumulated partial results via [`merge`]. This makes it possible
+/// to stream data or to compute moments of parallel chunks of an array and reduce them into a
+/// single, exact result in O(1) memory per accumulator.
+///
+/// The single-pass update and the merge use the recurrences from Section 3 of
+/// [Pébay et al., 2016].
+///
+/// [`SummaryStatisticsExt::central_moment`]: crate::SummaryStatisticsExt::central_moment
+/// [`push`]: MomentsAccumulator::push
+/// [`merge`]: MomentsAccumulator::merge
+/// [Pébay et al., 2016]: https://www.osti.gov/pages/servlets/purl/1427275
+#[derive(Clone, Debug)]
+pub struct MomentsAccumulator<A> {
+    order: u16,
+    n: u64,
+    mean: A,
+    // `moments[p]` holds M_p = Σ(xᵢ-mean)ᵖ for p in 2..=order. Indices 0 and 1 are unused.
+    moments: Vec<A>,
+}
+
+impl<A> MomentsAccumulator<A>
+where
+    A: Float + FromPrimitive + Zero,
+{
+    /// Creates a new, empty accumulator tracking central moments up to `order` (order ≥ 2).
+    ///
+    /// **Panics** if `order` is less than 2.
+    pub fn new(order: u16) -> Self {
+        assert!(order >= 2, "`order` must be at least 2");
+        MomentsAccumulator {
+            order,
+            n: 0,
+            mean: A::zero(),
+            moments: vec![A::zero(); order as usize + 1],
+        }
+    }
+
+    /// The number of values seen so far.
+    pub fn len(&self) -> u64 {
+        self.n
+    }
+
+    /// Returns `true` if no value has been pushed yet.
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Updates the accumulator with a single new value, in time that depends only on `order`.
+    pub fn push(&mut self, x: A) {
+        let n1 = self.n;
+        self.n += 1;
+        let n = A::from_u64(self.n).unwrap();
+        let delta = x - self.mean;
+        let delta_n = delta / n;
+        let n1 = A::from_u64(n1).unwrap();
+
+        // The old deviations (xᵢ-mean_old) shift by -δₙ to become (xᵢ-mean_new); expanding that
+        // shift via the binomial theorem and adding the new point's own contribution,
+        // (n1·δₙ)ᵖ, yields the updated M_p. M_0 = n1 and M_1 = 0 by definition.
+        for p in (2..=self.order as usize).rev() {
+            let mut m_p = (n1 * delta_n).powi(p as i32);
+            for k in 0..=p {
+                let old_m = match p - k {
+                    0 => n1,
+                    1 => A::zero(),
+                    j => self.moments[j],
+                };
+                m_p = m_p
+                    + A::from_u64(binomial(p as u64, k as u64)).unwrap()
+                        * old_m
+                        * (-delta_n).powi(k as i32);
+            }
+            self.moments[p] = m_p;
+        }
+        self.mean = self.mean + delta_n;
+    }
+
+    /// Combines `other` into `self`, as if every value pushed into `other` had been pushed
+    /// into `self` directly. This is exact, not an approximation, and runs in time that depends
+    /// only on `order`, regardless of how many values either accumulator has seen.
+    ///
+    /// **Panics** if `self` and `other` were created with different `order`s.
+    pub fn merge(&mut self, other: &Self) {
+        assert_eq!(
+            self.order, other.order,
+            "cannot merge accumulators tracking different orders"
+        );
+        if other.n == 0 {
+            return;
+        }
+        if self.n == 0 {
+            *self = other.clone();
+            return;
+        }
+
+        let n_a = A::from_u64(self.n).unwrap();
+        let n_b = A::from_u64(other.n).unwrap();
+        let n = n_a + n_b;
+        let delta = other.mean - self.mean;
+
+        // Sum over the *full* k=0..=p range: M_0 = n_A/n_B and M_1 = 0 by definition, mirroring
+        // the `match p - k { ... }` pattern `push` uses. The k=p terms, built on M_0 alone,
+        // supply the n_A·n_B·δ²/n-style cross term that makes the merge exact.
+        let mut merged = vec![A::zero(); self.moments.len()];
+        for (p, slot) in merged.iter_mut().enumerate().skip(2) {
+            let mut m_p = A::zero();
+            for k in 0..=p {
+                let c = A::from_u64(binomial(p as u64, k as u64)).unwrap();
+                let old_a = match p - k {
+                    0 => n_a,
+                    1 => A::zero(),
+                    j => self.moments[j],
+                };
+                let old_b = match p - k {
+                    0 => n_b,
+                    1 => A::zero(),
+                    j => other.moments[j],
+                };
+                let term_a = old_a * (-(n_b * delta / n)).powi(k as i32);
+                let term_b = old_b * (n_a * delta / n).powi(k as i32);
+                m_p = m_p + c * (term_a + term_b);
+            }
+            *slot = m_p;
+        }
+
+        self.moments = merged;
+        self.mean = self.mean + (n_b / n) * delta;
+        self.n += other.n;
+    }
+
+    /// Returns the mean of all values seen so far.
+    ///
+    /// If no value has been pushed yet, `Err(EmptyInput)` is returned.
+    pub fn mean(&self) -> Result<A, EmptyInput> {
+        if self.n == 0 {
+            return Err(EmptyInput);
+        }
+        Ok(self.mean)
+    }
+
+    /// Returns the *p*-th central moment, μₚ = (1/n)·M_p, of all values seen so far.
+    ///
+    /// If no value has been pushed yet, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `order` is greater than the order this accumulator was created with.
+    pub fn central_moment(&self, order: u16) -> Result<A, EmptyInput> {
+        if self.n == 0 {
+            return Err(EmptyInput);
+        }
+        match order {
+            0 => Ok(A::one()),
+            1 => Ok(A::zero()),
+            p if p <= self.order => Ok(self.moments[p as usize] / A::from_u64(self.n).unwrap()),
+            _ => panic!("this accumulator does not track moments of order {}", order),
+        }
+    }
+
+    /// Returns the variance (the 2nd central moment) of all values seen so far.
+    ///
+    /// If no value has been pushed yet, `Err(EmptyInput)` is returned.
+    pub fn variance(&self) -> Result<A, EmptyInput> {
+        self.central_moment(2)
+    }
+
+    /// Returns Pearson's moment coefficient of skewness, γ₁ = μ₃/σ³, of all values seen so far.
+    ///
+    /// If no value has been pushed yet, `Err(EmptyInput)` is returned.
+    pub fn skewness(&self) -> Result<A, EmptyInput> {
+        let variance = self.variance()?;
+        let moment_3 = self.central_moment(3)?;
+        Ok(moment_3 / variance.powf(A::from_f64(1.5).unwrap()))
+    }
+
+    /// Returns Pearson's kurtosis, Kurt[X] = μ₄/σ⁴, of all values seen so far.
+    ///
+    /// If no value has been pushed yet, `Err(EmptyInput)` is returned.
+    pub fn kurtosis(&self) -> Result<A, EmptyInput> {
+        let variance = self.variance()?;
+        let moment_4 = self.central_moment(4)?;
+        Ok(moment_4 / (variance * variance))
+    }
+}
+
+/// Computes the binomial coefficient `n choose k` via the multiplicative formula
+/// `∏ (n-i+1)/i` for `i` in `1..=k`.
+fn binomial(n: u64, k: u64) -> u64 {
+    if k == 0 || k == n {
+        return 1;
+    }
+    (1..=k).fold(1u64, |acc, i| acc * (n - i + 1) / i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MomentsAccumulator;
+
+    /// Computes the *p*-th central moment of `values` directly, via a two-pass sum, to use as
+    /// a ground truth for the online accumulator.
+    fn direct_central_moment(values: &[f64], order: i32) -> f64 {
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / n;
+        values.iter().map(|&x| (x - mean).powi(order)).sum::<f64>() / n
+    }
+
+    #[test]
+    fn merge_matches_direct_computation() {
+        let a = [1.0, 2.0, 5.0];
+        let b = [10.0, 3.0, 7.0, 2.0];
+
+        let mut acc_a = MomentsAccumulator::new(4);
+        a.iter().for_each(|&x| acc_a.push(x));
+        let mut acc_b = MomentsAccumulator::new(4);
+        b.iter().for_each(|&x| acc_b.push(x));
+        acc_a.merge(&acc_b);
+
+        let all: Vec<f64> = a.iter().chain(b.iter()).copied().collect();
+        for order in 2..=4 {
+            let expected = direct_central_moment(&all, order);
+            let actual = acc_a.central_moment(order as u16).unwrap();
+            assert!(
+                (actual - expected).abs() < 1e-9,
+                "order {order}: expected {expected}, got {actual}"
+            );
+        }
+    }
+
+    #[test]
+    fn push_matches_direct_computation() {
+        let values = [1.0, 2.0, 5.0, 10.0, 3.0, 7.0, 2.0];
+        let mut acc = MomentsAccumulator::new(4);
+        values.iter().for_each(|&x| acc.push(x));
+
+        for order in 2..=4 {
+            let expected = direct_central_moment(&values, order);
+            let actual = acc.central_moment(order as u16).unwrap();
+            assert!(
+                (actual - expected).abs() < 1e-9,
+                "order {order}: expected {expected}, got {actual}"
+            );
+        }
+    }
+}
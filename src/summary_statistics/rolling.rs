@@ -0,0 +1,190 @@
+//! Sliding-window rolling statistics along an axis.
+use ndarray::{Array, ArrayBase, Axis, Data, Dimension, RemoveAxis, Zip};
+use num_traits::{Float, FromPrimitive};
+use std::collections::VecDeque;
+
+/// Extension trait for `ArrayBase` providing online rolling (windowed) summary statistics
+/// along an axis, computed incrementally rather than by recomputing from scratch at each step.
+///
+/// A `window` of `0` means an _expanding_ window: every step only inserts the incoming element,
+/// never ejecting one, so the *i*-th slice along `axis` of the result summarises all elements
+/// up to and including the *i*-th one. A `window` of `w > 0` means a _fixed-size_ window: once
+/// `w` elements have been inserted, each further step inserts the new element and then ejects
+/// the oldest one, so the window holds `w` elements after every such step. For a fixed-size
+/// window, the first `w - 1` entries along `axis` are dropped from the result, since no
+/// complete window of size `w` exists there yet.
+pub trait RollingSummaryStatisticsExt<A, S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension + RemoveAxis,
+{
+    /// Returns the rolling arithmetic mean of `self` along `axis`, using a window of size
+    /// `window` (`0` for an expanding window).
+    ///
+    /// **Panics** if `axis` is out of bounds, or if `A::from_usize()` fails to convert a
+    /// window length.
+    fn rolling_mean(&self, axis: Axis, window: usize) -> Array<A, D>
+    where
+        A: Float + FromPrimitive;
+
+    /// Returns the rolling variance of `self` along `axis`, using a window of size `window`
+    /// (`0` for an expanding window).
+    ///
+    /// **Panics** if `axis` is out of bounds, or if `A::from_usize()` fails to convert a
+    /// window length.
+    fn rolling_variance(&self, axis: Axis, window: usize) -> Array<A, D>
+    where
+        A: Float + FromPrimitive;
+}
+
+impl<A, S, D> RollingSummaryStatisticsExt<A, S, D> for ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension + RemoveAxis,
+{
+    fn rolling_mean(&self, axis: Axis, window: usize) -> Array<A, D>
+    where
+        A: Float + FromPrimitive,
+    {
+        roll(self, axis, window, |means, _| means)
+    }
+
+    fn rolling_variance(&self, axis: Axis, window: usize) -> Array<A, D>
+    where
+        A: Float + FromPrimitive,
+    {
+        roll(self, axis, window, |_, variances| variances)
+    }
+}
+
+/// Applies the rolling Welford fold to every 1-D lane of `arr` along `axis`, and collects
+/// the half of the result (means or variances) selected by `pick`.
+fn roll<A, S, D>(
+    arr: &ArrayBase<S, D>,
+    axis: Axis,
+    window: usize,
+    pick: impl Fn(Vec<A>, Vec<A>) -> Vec<A>,
+) -> Array<A, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension + RemoveAxis,
+    A: Float + FromPrimitive,
+{
+    let out_len = if window == 0 {
+        arr.len_of(axis)
+    } else {
+        arr.len_of(axis).saturating_sub(window - 1)
+    };
+    let mut out_shape = arr.raw_dim();
+    out_shape[axis.index()] = out_len;
+    let mut out = Array::zeros(out_shape);
+
+    Zip::from(arr.lanes(axis))
+        .and(out.lanes_mut(axis))
+        .for_each(|lane, mut out_lane| {
+            let (means, variances) = rolling_welford(lane.iter().copied(), window);
+            let selected = pick(means, variances);
+            for (o, v) in out_lane.iter_mut().zip(selected) {
+                *o = v;
+            }
+        });
+    out
+}
+
+/// Runs the insert-then-eject Welford fold over `values`, returning one `(mean, variance)`
+/// pair per completed window.
+fn rolling_welford<A: Float + FromPrimitive>(
+    values: impl Iterator<Item = A>,
+    window: usize,
+) -> (Vec<A>, Vec<A>) {
+    let expanding = window == 0;
+    let mut means = Vec::new();
+    let mut variances = Vec::new();
+    let mut queue: VecDeque<A> = VecDeque::new();
+    let mut n = 0usize;
+    let mut mean = A::zero();
+    let mut m2 = A::zero();
+
+    for x_in in values {
+        // insert
+        n += 1;
+        let delta = x_in - mean;
+        mean = mean
+            + delta
+                / A::from_usize(n).expect("Converting number of elements to `A` must not fail.");
+        m2 = m2 + delta * (x_in - mean);
+        queue.push_back(x_in);
+
+        // eject, for fixed-size windows only
+        if !expanding && queue.len() > window {
+            let x_out = queue.pop_front().unwrap();
+            n -= 1;
+            let delta_out = x_out - mean;
+            mean = mean
+                - delta_out
+                    / A::from_usize(n)
+                        .expect("Converting number of elements to `A` must not fail.");
+            m2 = m2 - delta_out * (x_out - mean);
+        }
+
+        if expanding || queue.len() == window {
+            means.push(mean);
+            variances.push(
+                m2 / A::from_usize(n).expect("Converting number of elements to `A` must not fail."),
+            );
+        }
+    }
+    (means, variances)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RollingSummaryStatisticsExt;
+    use ndarray::{array, Axis};
+
+    /// Computes the naive (mean, variance) of a fixed-size window directly, to use as a
+    /// ground truth for the online insert/eject fold.
+    fn direct_window_stats(window: &[f64]) -> (f64, f64) {
+        let n = window.len() as f64;
+        let mean = window.iter().sum::<f64>() / n;
+        let variance = window.iter().map(|&x| (x - mean).powi(2)).sum::<f64>() / n;
+        (mean, variance)
+    }
+
+    #[test]
+    fn fixed_window_matches_direct_computation() {
+        let data = array![1.0, 5.0, 2.0, 8.0, 3.0, 9.0, 4.0];
+        let window = 3;
+
+        let rolling_means = data.rolling_mean(Axis(0), window);
+        let rolling_variances = data.rolling_variance(Axis(0), window);
+
+        let expected: Vec<(f64, f64)> = data
+            .as_slice()
+            .unwrap()
+            .windows(window)
+            .map(direct_window_stats)
+            .collect();
+
+        assert_eq!(rolling_means.len(), expected.len());
+        for (i, &(expected_mean, expected_variance)) in expected.iter().enumerate() {
+            assert!((rolling_means[i] - expected_mean).abs() < 1e-9);
+            assert!((rolling_variances[i] - expected_variance).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn expanding_window_matches_direct_computation() {
+        let data = array![1.0, 5.0, 2.0, 8.0, 3.0];
+
+        let rolling_means = data.rolling_mean(Axis(0), 0);
+        let rolling_variances = data.rolling_variance(Axis(0), 0);
+
+        for i in 0..data.len() {
+            let (expected_mean, expected_variance) =
+                direct_window_stats(&data.as_slice().unwrap()[..=i]);
+            assert!((rolling_means[i] - expected_mean).abs() < 1e-9);
+            assert!((rolling_variances[i] - expected_variance).abs() < 1e-9);
+        }
+    }
+}
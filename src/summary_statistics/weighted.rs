@@ -0,0 +1,178 @@
+//! Weighted summary statistics.
+use crate::errors::EmptyInput;
+use ndarray::{ArrayBase, Data, Dimension, Zip};
+use num_traits::{Float, FromPrimitive};
+
+/// Extension trait for `ArrayBase` providing methods to compute weighted summary statistics
+/// (e.g. weighted mean, weighted variance, etc.), where `weights` assigns each element its own
+/// weight instead of treating all elements as equally significant, unlike [`SummaryStatisticsExt`].
+///
+/// [`SummaryStatisticsExt`]: crate::SummaryStatisticsExt
+pub trait WeightedSummaryStatisticsExt<A, S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Returns the weighted arithmetic mean of all elements in the array, using `weights` as
+    /// the weight of the element at the same index.
+    ///
+    /// If the array is empty or the sum of the weights is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `self` and `weights` don't have the same shape.
+    fn weighted_mean<S2>(&self, weights: &ArrayBase<S2, D>) -> Result<A, EmptyInput>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive;
+
+    /// Returns the weighted variance of all elements in the array, using `weights` as the
+    /// weight of the element at the same index.
+    ///
+    /// If the array is empty or the sum of the weights is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `self` and `weights` don't have the same shape.
+    fn weighted_variance<S2>(&self, weights: &ArrayBase<S2, D>) -> Result<A, EmptyInput>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive;
+
+    /// Returns the weighted *p*-th central moment of all elements in the array, using `weights`
+    /// as the weight of the element at the same index.
+    ///
+    /// If the array is empty or the sum of the weights is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `self` and `weights` don't have the same shape.
+    fn weighted_central_moment<S2>(
+        &self,
+        order: u16,
+        weights: &ArrayBase<S2, D>,
+    ) -> Result<A, EmptyInput>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive;
+}
+
+impl<A, S, D> WeightedSummaryStatisticsExt<A, S, D> for ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn weighted_mean<S2>(&self, weights: &ArrayBase<S2, D>) -> Result<A, EmptyInput>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive,
+    {
+        Ok(weighted_welford(self, weights)?.1)
+    }
+
+    fn weighted_variance<S2>(&self, weights: &ArrayBase<S2, D>) -> Result<A, EmptyInput>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive,
+    {
+        let (w_sum, _, s) = weighted_welford(self, weights)?;
+        Ok(s / w_sum)
+    }
+
+    fn weighted_central_moment<S2>(
+        &self,
+        order: u16,
+        weights: &ArrayBase<S2, D>,
+    ) -> Result<A, EmptyInput>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive,
+    {
+        let (w_sum, mean, _) = weighted_welford(self, weights)?;
+        if order == 0 {
+            return Ok(A::one());
+        }
+        let mut sum = A::zero();
+        Zip::from(self).and(weights).for_each(|&x, &w| {
+            sum = sum + w * (x - mean).powi(order as i32);
+        });
+        Ok(sum / w_sum)
+    }
+}
+
+/// Runs the weighted Welford single-pass update, returning `(w_sum, mean, S)` where
+/// `S = Σ wᵢ·(xᵢ-mean)·(xᵢ-mean_updated)` accumulates so that `S/w_sum` is the weighted variance.
+fn weighted_welford<A, S, S2, D>(
+    values: &ArrayBase<S, D>,
+    weights: &ArrayBase<S2, D>,
+) -> Result<(A, A, A), EmptyInput>
+where
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+    A: Float + FromPrimitive,
+{
+    assert_eq!(
+        values.shape(),
+        weights.shape(),
+        "`values` and `weights` must have the same shape"
+    );
+    let mut w_sum = A::zero();
+    let mut mean = A::zero();
+    let mut s = A::zero();
+    Zip::from(values).and(weights).for_each(|&x, &w| {
+        // A zero weight contributes nothing, and would divide 0/0 while `w_sum` is still zero.
+        if w.is_zero() {
+            return;
+        }
+        w_sum = w_sum + w;
+        let delta = x - mean;
+        mean = mean + (w / w_sum) * delta;
+        s = s + w * delta * (x - mean);
+    });
+    if w_sum.is_zero() {
+        return Err(EmptyInput);
+    }
+    Ok((w_sum, mean, s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WeightedSummaryStatisticsExt;
+    use ndarray::array;
+
+    /// Computes the weighted mean and variance directly, to use as a ground truth for the
+    /// online weighted Welford update.
+    fn direct_weighted_stats(values: &[f64], weights: &[f64]) -> (f64, f64) {
+        let w_sum: f64 = weights.iter().sum();
+        let mean: f64 = values
+            .iter()
+            .zip(weights)
+            .map(|(&x, &w)| w * x)
+            .sum::<f64>()
+            / w_sum;
+        let variance: f64 = values
+            .iter()
+            .zip(weights)
+            .map(|(&x, &w)| w * (x - mean).powi(2))
+            .sum::<f64>()
+            / w_sum;
+        (mean, variance)
+    }
+
+    #[test]
+    fn weighted_mean_and_variance_match_direct_computation() {
+        let values = [1.0, 2.0, 5.0, 10.0];
+        let weights = [1.0, 3.0, 0.5, 2.0];
+        let (expected_mean, expected_variance) = direct_weighted_stats(&values, &weights);
+
+        let values = array![1.0, 2.0, 5.0, 10.0];
+        let weights = array![1.0, 3.0, 0.5, 2.0];
+        assert!((values.weighted_mean(&weights).unwrap() - expected_mean).abs() < 1e-9);
+        assert!((values.weighted_variance(&weights).unwrap() - expected_variance).abs() < 1e-9);
+        assert!(
+            (values.weighted_central_moment(2, &weights).unwrap() - expected_variance).abs() < 1e-9
+        );
+    }
+
+    #[test]
+    fn zero_weights_is_an_error() {
+        let values = array![1.0, 2.0, 3.0];
+        let weights = array![0.0, 0.0, 0.0];
+        assert!(values.weighted_mean(&weights).is_err());
+    }
+}